@@ -0,0 +1,331 @@
+use std::collections::HashMap;
+use std::sync::mpsc;
+
+use bevy_ecs::{
+    event::{Event, EventReader, EventWriter},
+    system::NonSend,
+};
+use bevy_utils::tracing::{
+    field::{Field, Visit},
+    Event as TracingEvent, Level, Subscriber,
+};
+use tracing_subscriber::{
+    filter::LevelFilter,
+    layer::{Context, Layer},
+};
+
+/// A log message captured from a [`tracing`](bevy_utils::tracing) event and forwarded into
+/// Bevy's ECS by [`LogPlugin`](crate::LogPlugin).
+///
+/// Besides the formatted `message`, every other structured field recorded on the originating
+/// event (e.g. `info!(player_id = 3, "joined")`) is kept in [`LogEvent::fields`], keyed by field
+/// name, so systems can filter on them instead of re-parsing the message string.
+#[derive(Debug, Clone, Event)]
+pub struct LogEvent {
+    /// The formatted `message` field of the event.
+    pub message: String,
+    /// Every field on the event other than `message`, formatted to a string.
+    pub fields: HashMap<String, String>,
+    /// The name of the span or event.
+    pub name: &'static str,
+    /// The name of the target (usually the module path) the event was emitted from.
+    pub target: String,
+    /// The verbosity level of the event.
+    pub level: Level,
+    /// The module path the event was emitted from, if available.
+    pub module_path: Option<String>,
+    /// The source file the event was emitted from, if available.
+    pub file: Option<String>,
+    /// The line number the event was emitted from, if available.
+    pub line: Option<u32>,
+}
+
+/// Temporarily stores [`LogEvent`]s received from [`CaptureLayer`] before
+/// [`transfer_log_events`] writes them into [`Events<LogEvent>`](bevy_ecs::event::Events).
+///
+/// This has to be a non-send resource because [`mpsc::Receiver`] is [`!Sync`](Sync).
+pub(crate) struct CapturedLogEvents(pub(crate) mpsc::Receiver<LogEvent>);
+
+/// Drains [`CapturedLogEvents`] into [`Events<LogEvent>`](bevy_ecs::event::Events) once per
+/// frame.
+pub(crate) fn transfer_log_events(
+    receiver: NonSend<CapturedLogEvents>,
+    mut log_events: EventWriter<LogEvent>,
+) {
+    // Make sure to use `try_iter()` and not `iter()` to prevent blocking.
+    log_events.send_batch(receiver.0.try_iter());
+}
+
+/// Configures which `tracing` levels are forwarded to the ECS as [`LogEvent`]s.
+///
+/// This is applied on top of [`LogPlugin::filter`](crate::LogPlugin::filter)/[`LogPlugin::level`](crate::LogPlugin::level):
+/// those install a global `EnvFilter` that decides which events reach any layer at all, stderr
+/// output and ECS capture alike, so this can only narrow what the ECS sees further, never widen
+/// it. Events below the configured level are dropped inside [`CaptureLayer`] itself, before a [`LogEvent`] is even built, so a console that
+/// only cares about `WARN`-and-above doesn't pay to format and channel-send `TRACE` spam.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogLevelFilter(LevelFilter);
+
+impl LogLevelFilter {
+    /// Forward every level to the ECS. This is the default.
+    pub fn all() -> Self {
+        Self(LevelFilter::TRACE)
+    }
+
+    /// Only forward events at least as severe as `level`.
+    pub fn at_least(level: Level) -> Self {
+        Self(LevelFilter::from_level(level))
+    }
+
+    /// Forward nothing to the ECS.
+    pub fn none() -> Self {
+        Self(LevelFilter::OFF)
+    }
+}
+
+impl Default for LogLevelFilter {
+    fn default() -> Self {
+        Self::all()
+    }
+}
+
+/// Builds a run condition that's `true` whenever an unread [`LogEvent`] at least as severe as
+/// `min_level` is waiting, so a system can declare interest in, say, only `WARN`-and-above
+/// without every `EventReader<LogEvent>` re-implementing the same `event.level <= min_level`
+/// check in its own body.
+///
+/// This is complementary to [`LogLevelFilter`], not a replacement for it: [`LogLevelFilter`] is a
+/// single global cutoff that stops events from ever being formatted and forwarded to *any*
+/// consumer, while this lets individual systems skip their own work on frames that only carried
+/// events below their own, stricter threshold. Setting [`LogLevelFilter`] to `WARN` so one
+/// console can see warnings still means no other system can ever see an `INFO` [`LogEvent`]; this
+/// run condition leaves every `LogEvent` reaching the ECS and just gates individual systems.
+///
+/// Each call returns an independent condition with its own read cursor into
+/// [`Events<LogEvent>`](bevy_ecs::event::Events), so using it on several systems doesn't cause
+/// them to race over which one "consumes" a given event first.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_log::{on_log_event_at_least, Level};
+/// # fn alert_on_loud_logs() {}
+/// # let mut app = App::new();
+/// app.add_systems(
+///     Update,
+///     alert_on_loud_logs.run_if(on_log_event_at_least(Level::WARN)),
+/// );
+/// ```
+pub fn on_log_event_at_least(min_level: Level) -> impl FnMut(EventReader<LogEvent>) -> bool {
+    move |mut events: EventReader<LogEvent>| events.read().any(|event| event.level <= min_level)
+}
+
+/// The [`Layer`] installed by [`LogPlugin`](crate::LogPlugin) that captures `tracing` events
+/// passing [`LogLevelFilter`] and sends them across an [`mpsc::channel`] to [`CapturedLogEvents`].
+pub(crate) struct CaptureLayer {
+    pub(crate) sender: mpsc::Sender<LogEvent>,
+    pub(crate) ecs_level_filter: LogLevelFilter,
+}
+
+impl<S: Subscriber> Layer<S> for CaptureLayer {
+    fn on_event(&self, event: &TracingEvent<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+
+        if *metadata.level() > self.ecs_level_filter.0 {
+            return;
+        }
+
+        let mut visitor = LogEventVisitor::default();
+        event.record(&mut visitor);
+
+        let log_event = LogEvent {
+            message: visitor.message.unwrap_or_default(),
+            fields: visitor.fields,
+            name: metadata.name(),
+            target: metadata.target().to_string(),
+            level: *metadata.level(),
+            module_path: metadata.module_path().map(str::to_owned),
+            file: metadata.file().map(str::to_owned),
+            line: metadata.line(),
+        };
+
+        // The channel's receiver may already be gone during app shutdown; that's fine, we just
+        // drop the event rather than panicking from inside a tracing layer.
+        let _ = self.sender.send(log_event);
+    }
+}
+
+/// A [`Visit`]or that records the `message` field separately from every other field, since
+/// `tracing` gives each field's value to us one at a time with no common storage type.
+#[derive(Default)]
+struct LogEventVisitor {
+    message: Option<String>,
+    fields: HashMap<String, String>,
+}
+
+impl LogEventVisitor {
+    fn record(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = Some(value);
+        } else {
+            self.fields.insert(field.name().to_string(), value);
+        }
+    }
+}
+
+impl Visit for LogEventVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.record(field, format!("{value:?}"));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_i64(&mut self, field: &Field, value: i64) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_u64(&mut self, field: &Field, value: u64) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_bool(&mut self, field: &Field, value: bool) {
+        self.record(field, value.to_string());
+    }
+
+    fn record_f64(&mut self, field: &Field, value: f64) {
+        self.record(field, value.to_string());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{event::Events, system::SystemState, world::World};
+    use bevy_utils::tracing;
+    use tracing_subscriber::{layer::SubscriberExt, Registry};
+
+    fn log_event(level: Level) -> LogEvent {
+        LogEvent {
+            message: "test".to_string(),
+            fields: HashMap::new(),
+            name: "test",
+            target: "test".to_string(),
+            level,
+            module_path: None,
+            file: None,
+            line: None,
+        }
+    }
+
+    #[test]
+    fn captures_every_field_except_message() {
+        let (sender, receiver) = mpsc::channel();
+        let layer = CaptureLayer {
+            sender,
+            ecs_level_filter: LogLevelFilter::all(),
+        };
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!(player_id = 3, health = 12.5, "joined the game");
+        });
+
+        let log_event = receiver.try_recv().expect("event should have been captured");
+        assert_eq!(log_event.message, "joined the game");
+        assert_eq!(
+            log_event.fields.get("player_id").map(String::as_str),
+            Some("3")
+        );
+        assert_eq!(
+            log_event.fields.get("health").map(String::as_str),
+            Some("12.5")
+        );
+        assert!(!log_event.fields.contains_key("message"));
+    }
+
+    #[test]
+    fn level_filter_drops_events_before_they_reach_the_channel() {
+        let (sender, receiver) = mpsc::channel();
+        let layer = CaptureLayer {
+            sender,
+            ecs_level_filter: LogLevelFilter::at_least(Level::WARN),
+        };
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::info!("too quiet to matter");
+            tracing::warn!("loud enough to forward");
+        });
+
+        let log_event = receiver
+            .try_recv()
+            .expect("the WARN event should have been captured");
+        assert_eq!(log_event.message, "loud enough to forward");
+        assert!(
+            receiver.try_recv().is_err(),
+            "the INFO event should have been dropped before reaching the channel"
+        );
+    }
+
+    #[test]
+    fn level_filter_none_forwards_nothing() {
+        let (sender, receiver) = mpsc::channel();
+        let layer = CaptureLayer {
+            sender,
+            ecs_level_filter: LogLevelFilter::none(),
+        };
+        let subscriber = Registry::default().with(layer);
+
+        tracing::subscriber::with_default(subscriber, || {
+            tracing::error!("should still be dropped");
+        });
+
+        assert!(receiver.try_recv().is_err());
+    }
+
+    #[test]
+    fn on_log_event_at_least_ignores_events_below_the_threshold() {
+        let mut world = World::new();
+        world.init_resource::<Events<LogEvent>>();
+        let mut condition = on_log_event_at_least(Level::WARN);
+        let mut state: SystemState<EventReader<LogEvent>> = SystemState::new(&mut world);
+
+        world.resource_mut::<Events<LogEvent>>().send(log_event(Level::INFO));
+        assert!(!condition(state.get_mut(&mut world)));
+    }
+
+    #[test]
+    fn on_log_event_at_least_fires_once_a_severe_enough_event_arrives() {
+        let mut world = World::new();
+        world.init_resource::<Events<LogEvent>>();
+        let mut condition = on_log_event_at_least(Level::WARN);
+        let mut state: SystemState<EventReader<LogEvent>> = SystemState::new(&mut world);
+
+        world.resource_mut::<Events<LogEvent>>().send(log_event(Level::INFO));
+        assert!(!condition(state.get_mut(&mut world)));
+
+        world.resource_mut::<Events<LogEvent>>().send(log_event(Level::ERROR));
+        assert!(condition(state.get_mut(&mut world)));
+
+        // The condition only reports on events it hasn't seen yet, same as any other
+        // `EventReader`, so a second call with nothing new queued goes back to `false`.
+        assert!(!condition(state.get_mut(&mut world)));
+    }
+
+    #[test]
+    fn on_log_event_at_least_gives_each_call_an_independent_cursor() {
+        let mut world = World::new();
+        world.init_resource::<Events<LogEvent>>();
+        let mut strict = on_log_event_at_least(Level::ERROR);
+        let mut lenient = on_log_event_at_least(Level::INFO);
+        let mut state: SystemState<EventReader<LogEvent>> = SystemState::new(&mut world);
+
+        world.resource_mut::<Events<LogEvent>>().send(log_event(Level::WARN));
+        assert!(!strict(state.get_mut(&mut world)));
+
+        let mut state: SystemState<EventReader<LogEvent>> = SystemState::new(&mut world);
+        assert!(lenient(state.get_mut(&mut world)));
+    }
+}
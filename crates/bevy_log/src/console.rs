@@ -0,0 +1,171 @@
+use std::collections::HashMap;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    event::{Event, EventReader},
+    system::{Res, Resource},
+};
+
+use crate::{error, info};
+
+/// Implemented by zero-sized marker types that name a registered console command, mirroring how
+/// Bevy labels systems with marker types elsewhere (e.g. [`SystemSet`](bevy_ecs::schedule::SystemSet)).
+pub trait ConsoleCommand: Send + Sync + 'static {
+    /// The token users type at the console to invoke this command, e.g. `"echo"`.
+    const NAME: &'static str;
+}
+
+type ConsoleCommandHandler = Box<dyn Fn(&[String]) -> Result<String, String> + Send + Sync>;
+
+/// Maps a console command's name token to the handler registered for it with
+/// [`AddConsoleCommand::add_console_command`].
+#[derive(Resource, Default)]
+pub struct CommandRegistry {
+    handlers: HashMap<&'static str, ConsoleCommandHandler>,
+}
+
+impl CommandRegistry {
+    /// The name tokens of every registered command. Order is not guaranteed.
+    pub fn command_names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.handlers.keys().copied()
+    }
+}
+
+/// Fired once a line of console input is submitted; `name` is the first whitespace-separated
+/// token and `args` is every token after it.
+#[derive(Debug, Clone, Event)]
+pub struct ExecuteCommand {
+    /// The command name token, e.g. `"echo"`.
+    pub name: String,
+    /// The remaining whitespace-split tokens.
+    pub args: Vec<String>,
+}
+
+impl ExecuteCommand {
+    /// Splits a raw console input line into an [`ExecuteCommand`]. Returns `None` for a blank
+    /// (or whitespace-only) line, which isn't treated as an error.
+    pub fn parse(line: &str) -> Option<Self> {
+        let mut tokens = line.split_whitespace();
+        let name = tokens.next()?.to_string();
+        let args = tokens.map(str::to_string).collect();
+        Some(Self { name, args })
+    }
+}
+
+/// Extension trait for registering console commands on an [`App`].
+pub trait AddConsoleCommand {
+    /// Registers `handler` to run whenever an [`ExecuteCommand`] event names `C::NAME`.
+    ///
+    /// The handler's `Ok(output)` or `Err(message)` is logged at `INFO`/`ERROR` respectively,
+    /// which also makes it show up as a [`LogEvent`](crate::LogEvent), the same as any other log
+    /// line.
+    fn add_console_command<C: ConsoleCommand>(
+        &mut self,
+        handler: impl Fn(&[String]) -> Result<String, String> + Send + Sync + 'static,
+    ) -> &mut Self;
+}
+
+impl AddConsoleCommand for App {
+    fn add_console_command<C: ConsoleCommand>(
+        &mut self,
+        handler: impl Fn(&[String]) -> Result<String, String> + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.init_resource::<CommandRegistry>();
+        self.world
+            .resource_mut::<CommandRegistry>()
+            .handlers
+            .insert(C::NAME, Box::new(handler));
+        self
+    }
+}
+
+/// Dispatches queued [`ExecuteCommand`] events against [`CommandRegistry`], logging the result
+/// (or failure) as a regular tracing event so it flows into [`LogEvent`](crate::LogEvent) like
+/// anything else written with `info!`/`error!`.
+pub(crate) fn execute_console_commands_system(
+    mut events: EventReader<ExecuteCommand>,
+    registry: Res<CommandRegistry>,
+) {
+    for ExecuteCommand { name, args } in events.read() {
+        if name == "help" {
+            let mut names: Vec<_> = registry.command_names().chain(["help"]).collect();
+            names.sort_unstable();
+            names.dedup();
+            info!("available commands: {}", names.join(", "));
+            continue;
+        }
+
+        match registry.handlers.get(name.as_str()) {
+            Some(handler) => match handler(args) {
+                Ok(output) => info!("{output}"),
+                Err(error) => error!("`{name}`: {error}"),
+            },
+            None => error!("unknown command `{name}`"),
+        }
+    }
+}
+
+/// Marker type for the built-in `echo` command, registered by [`ConsolePlugin`].
+struct Echo;
+impl ConsoleCommand for Echo {
+    const NAME: &'static str = "echo";
+}
+
+/// Adds the in-game command console: the [`CommandRegistry`], [`ExecuteCommand`] dispatch, and
+/// the built-in `help` and `echo` commands.
+///
+/// This only wires up the command side of the console. Pair it with `bevy_ui`'s console input
+/// widget (a `TextBundle` that parses submitted lines into [`ExecuteCommand`]s) to get a
+/// self-contained debug console without pulling in an external crate.
+pub struct ConsolePlugin;
+
+impl Plugin for ConsolePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<ExecuteCommand>()
+            .init_resource::<CommandRegistry>()
+            .add_console_command::<Echo>(|args| Ok(args.join(" ")))
+            .add_systems(Update, execute_console_commands_system);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_splits_name_and_args() {
+        let command = ExecuteCommand::parse("echo hello world").unwrap();
+        assert_eq!(command.name, "echo");
+        assert_eq!(command.args, vec!["hello".to_string(), "world".to_string()]);
+    }
+
+    #[test]
+    fn parse_collapses_repeated_whitespace() {
+        let command = ExecuteCommand::parse("  help   ").unwrap();
+        assert_eq!(command.name, "help");
+        assert!(command.args.is_empty());
+    }
+
+    #[test]
+    fn parse_rejects_a_blank_line() {
+        assert!(ExecuteCommand::parse("   ").is_none());
+        assert!(ExecuteCommand::parse("").is_none());
+    }
+
+    #[test]
+    fn add_console_command_registers_a_callable_handler() {
+        let mut app = App::new();
+        app.add_console_command::<Echo>(|args| Ok(args.join(" ")));
+
+        let registry = app.world.resource::<CommandRegistry>();
+        let handler = registry
+            .handlers
+            .get(Echo::NAME)
+            .expect("echo should be registered");
+        assert_eq!(
+            handler(&["hi".to_string(), "there".to_string()]),
+            Ok("hi there".to_string())
+        );
+        assert!(registry.command_names().any(|name| name == Echo::NAME));
+    }
+}
@@ -0,0 +1,82 @@
+use bevy_ecs::{
+    event::{Event, EventReader},
+    system::{Res, Resource},
+};
+use tracing_subscriber::{reload, registry::Registry, EnvFilter};
+
+use crate::warn;
+
+/// A [`Resource`] wrapping the [`reload::Handle`] for the [`EnvFilter`] that
+/// [`LogPlugin`](crate::LogPlugin) installs, so the active filter directives can be changed at
+/// runtime by firing [`SetLogFilter`] instead of restarting the app.
+#[derive(Resource, Clone)]
+pub struct LogFilterHandle(pub(crate) reload::Handle<EnvFilter, Registry>);
+
+/// Fire this event to change [`LogPlugin`](crate::LogPlugin)'s active [`EnvFilter`] directives
+/// at runtime, e.g. from an in-game console.
+///
+/// If `directives` fails to parse, the previous filter is kept and the parse error is logged at
+/// [`Level::WARN`](crate::Level) (which, like any other log, also shows up as a [`LogEvent`](crate::LogEvent)).
+#[derive(Debug, Clone, Event)]
+pub struct SetLogFilter(pub String);
+
+/// Attempts to replace `handle`'s active filter with `directives`, returning an error message
+/// (and leaving the previous filter in place) if they fail to parse or the reload layer is gone.
+fn reload_filter(handle: &LogFilterHandle, directives: &str) -> Result<(), String> {
+    let filter = EnvFilter::try_new(directives).map_err(|error| {
+        format!("invalid log filter directives `{directives}`: {error}, keeping the previous filter")
+    })?;
+
+    handle
+        .0
+        .reload(filter)
+        .map_err(|_| "could not reload log filter, the EnvFilter layer is gone".to_string())
+}
+
+/// Applies queued [`SetLogFilter`] events to [`LogFilterHandle`].
+pub(crate) fn apply_log_filter(
+    mut events: EventReader<SetLogFilter>,
+    handle: Option<Res<LogFilterHandle>>,
+) {
+    let Some(handle) = handle else {
+        return;
+    };
+
+    for SetLogFilter(directives) in events.read() {
+        if let Err(message) = reload_filter(&handle, directives) {
+            warn!("{message}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn current_filter_string(handle: &LogFilterHandle) -> String {
+        handle
+            .0
+            .with_current(ToString::to_string)
+            .expect("the reload layer should still be alive")
+    }
+
+    #[test]
+    fn reload_replaces_the_filter_on_valid_directives() {
+        let (_layer, handle) = reload::Layer::new(EnvFilter::new("info"));
+        let handle = LogFilterHandle(handle);
+
+        assert!(reload_filter(&handle, "debug").is_ok());
+        assert_eq!(current_filter_string(&handle), "debug");
+    }
+
+    #[test]
+    fn invalid_directives_are_rejected_and_keep_the_previous_filter() {
+        let (_layer, handle) = reload::Layer::new(EnvFilter::new("info"));
+        let handle = LogFilterHandle(handle);
+
+        let error = reload_filter(&handle, "not a valid directive!!")
+            .expect_err("garbage directives should fail to parse");
+        assert!(error.contains("invalid log filter directives"));
+        assert_eq!(current_filter_string(&handle), "info");
+    }
+}
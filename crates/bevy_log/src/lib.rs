@@ -0,0 +1,132 @@
+//! This crate provides logging functions and configuration for [Bevy](https://bevyengine.org)
+//! apps, and automatically configures platform specific log handlers (i.e. WASM or Android).
+//!
+//! It also bridges `tracing` events into Bevy's ECS: every event observed by the global
+//! subscriber that passes [`LogPlugin::ecs_level_filter`] is captured and re-emitted as a
+//! [`LogEvent`], so systems can react to log output
+//! (filtering a debug console, surfacing warnings in-game, etc.) without depending on a separate
+//! logging crate.
+//!
+//! The [`LogPlugin`] should not be added multiple times in the same process, as a global logger
+//! and subscriber can only be set once.
+
+mod capture;
+mod console;
+mod filter;
+
+pub use capture::{on_log_event_at_least, LogEvent, LogLevelFilter};
+pub use console::{AddConsoleCommand, CommandRegistry, ConsoleCommand, ConsolePlugin, ExecuteCommand};
+pub use filter::{LogFilterHandle, SetLogFilter};
+pub use bevy_utils::tracing::{
+    self, debug, debug_span, error, error_span, info, info_span, trace, trace_span, warn,
+    warn_span, Level, Subscriber,
+};
+pub use tracing_subscriber;
+
+use bevy_app::{App, Plugin, Update};
+use capture::{transfer_log_events, CaptureLayer, CapturedLogEvents};
+use filter::apply_log_filter;
+use std::sync::mpsc;
+use tracing_log::LogTracer;
+use tracing_subscriber::{filter::EnvFilter, prelude::*, reload, registry::Registry};
+
+/// The default [`EnvFilter`] directives applied to noisy third-party crates.
+pub const DEFAULT_FILTER: &str = "wgpu=error,naga=warn";
+
+/// A boxed [`Subscriber`], used so that [`LogPlugin::update_subscriber`] can wrap or replace the
+/// subscriber Bevy builds before it becomes the global default.
+pub type BoxedSubscriber = Box<dyn Subscriber + Send + Sync + 'static>;
+
+/// Adds logging to Bevy, and bridges every `tracing` event that passes its filters into the ECS
+/// as a [`LogEvent`].
+///
+/// # Example
+///
+/// ```
+/// # use bevy_app::{App, NoopPluginGroup as DefaultPlugins, PluginGroup};
+/// # use bevy_log::LogPlugin;
+/// App::new()
+///     .add_plugins(DefaultPlugins.set(LogPlugin {
+///         level: bevy_log::Level::DEBUG,
+///         filter: "wgpu=error,bevy_render=info".to_string(),
+///         update_subscriber: None,
+///         ecs_level_filter: bevy_log::LogLevelFilter::all(),
+///     }));
+/// ```
+pub struct LogPlugin {
+    /// Filters logs using the [`EnvFilter`] format.
+    pub filter: String,
+    /// Filters out logs that are "less than" the given level.
+    pub level: Level,
+    /// Optionally apply additional transformations to the `tracing` subscriber Bevy builds
+    /// before it is installed as the global default, e.g. adding your own
+    /// [`Layer`](tracing_subscriber::Layer)s.
+    pub update_subscriber: Option<fn(&mut App, BoxedSubscriber) -> BoxedSubscriber>,
+    /// Which levels get forwarded to the ECS as [`LogEvent`]s at all. Defaults to forwarding
+    /// everything; tighten it (e.g. [`LogLevelFilter::at_least(Level::WARN)`](LogLevelFilter::at_least))
+    /// to avoid paying to format and channel-send levels no reader cares about.
+    pub ecs_level_filter: LogLevelFilter,
+}
+
+impl Default for LogPlugin {
+    fn default() -> Self {
+        Self {
+            filter: DEFAULT_FILTER.to_string(),
+            level: Level::INFO,
+            update_subscriber: None,
+            ecs_level_filter: LogLevelFilter::all(),
+        }
+    }
+}
+
+impl Plugin for LogPlugin {
+    fn build(&self, app: &mut App) {
+        let (sender, receiver) = mpsc::channel();
+
+        app.add_event::<LogEvent>();
+        app.add_event::<SetLogFilter>();
+        app.insert_non_send_resource(CapturedLogEvents(receiver));
+        app.add_systems(Update, (transfer_log_events, apply_log_filter));
+
+        let default_filter = format!("{},{}", self.level, self.filter);
+        let filter_layer = EnvFilter::try_from_default_env()
+            .or_else(|_| EnvFilter::try_new(&default_filter))
+            .expect("EnvFilter initialization failed");
+        let (filter_layer, filter_handle) = reload::Layer::new(filter_layer);
+        app.insert_resource(LogFilterHandle(filter_handle));
+
+        let fmt_layer = tracing_subscriber::fmt::Layer::default().with_writer(std::io::stderr);
+        let capture_layer = CaptureLayer {
+            sender,
+            ecs_level_filter: self.ecs_level_filter,
+        };
+
+        let subscriber = Registry::default()
+            .with(filter_layer)
+            .with(fmt_layer)
+            .with(capture_layer);
+
+        let finished_subscriber = if let Some(update_subscriber) = self.update_subscriber {
+            update_subscriber(app, Box::new(subscriber))
+        } else {
+            Box::new(subscriber)
+        };
+
+        let logger_already_set = LogTracer::init().is_err();
+        let subscriber_already_set =
+            tracing::subscriber::set_global_default(finished_subscriber).is_err();
+
+        match (logger_already_set, subscriber_already_set) {
+            (true, true) => error!(
+                "Could not set global logger and tracing subscriber as they are already set. Consider disabling LogPlugin."
+            ),
+            (true, _) => error!(
+                "Could not set global logger as it is already set. Consider disabling LogPlugin."
+            ),
+            (_, true) => error!(
+                "Could not set global tracing subscriber as it is already set. Consider disabling LogPlugin."
+            ),
+            _ => (),
+        }
+    }
+}
@@ -129,6 +129,28 @@ impl Plugin for UiPlugin {
                 ui_focus_system.in_set(UiSystem::Focus).after(InputSystem),
             );
 
+        // `LogConsole` and `ConsoleInput` render their contents as `Text`, so (like every other
+        // text-dependent item in this crate) they only make sense with `bevy_text` enabled.
+        //
+        // `LogConsole` reads `bevy_log::LogEvent` and `ConsoleInput` dispatches through
+        // `bevy_log::ExecuteCommand`; `bevy_log::LogPlugin`/`ConsolePlugin` are the ones that
+        // normally register those events, but we register them here too so that adding
+        // `UiPlugin` alone (without separately opting into those plugins, e.g. an app installing
+        // its own subscriber instead of `LogPlugin`) never panics on the first `Update` tick.
+        #[cfg(feature = "bevy_text")]
+        app.add_event::<bevy_log::LogEvent>()
+            .add_event::<bevy_log::ExecuteCommand>()
+            .add_systems(
+                Update,
+                (
+                    widget::console_input_system,
+                    widget::update_log_console_system,
+                    widget::scroll_log_console_system,
+                    widget::render_log_console_system,
+                )
+                    .chain(),
+            );
+
         #[cfg(feature = "bevy_text")]
         app.register_type::<TextLayoutInfo>()
             .register_type::<TextFlags>();
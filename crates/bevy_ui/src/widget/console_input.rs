@@ -0,0 +1,54 @@
+use bevy_ecs::prelude::*;
+use bevy_input::{keyboard::KeyCode, Input};
+use bevy_log::ExecuteCommand;
+use bevy_text::Text;
+use bevy_window::ReceivedCharacter;
+
+/// Marker for a `Text` entity that acts as the command console's input line.
+///
+/// It accumulates typed characters and, on <kbd>Enter</kbd>, parses the accumulated line into an
+/// [`ExecuteCommand`] and sends it, the same event `bevy_log`'s [`ConsolePlugin`](bevy_log::ConsolePlugin)
+/// dispatches against the registered [`CommandRegistry`](bevy_log::CommandRegistry). The input
+/// line's own text is kept in sync with the buffer so it renders like a normal text field.
+#[derive(Component, Debug, Default)]
+pub struct ConsoleInput {
+    buffer: String,
+}
+
+/// Reads typed characters and <kbd>Enter</kbd> presses into the [`ConsoleInput`] entity, sending
+/// an [`ExecuteCommand`] once a line is submitted.
+pub fn console_input_system(
+    mut received_characters: EventReader<ReceivedCharacter>,
+    keys: Res<Input<KeyCode>>,
+    mut commands: EventWriter<ExecuteCommand>,
+    mut query: Query<(&mut ConsoleInput, &mut Text)>,
+) {
+    let Ok((mut input, mut text)) = query.get_single_mut() else {
+        return;
+    };
+
+    // `KeyCode::Back` is the single source of truth for deleting a character: on some platforms
+    // pressing backspace *also* fires a `ReceivedCharacter('\u{8}')` in the same frame, and
+    // handling both would delete two characters per physical key press.
+    for received_character in received_characters.read() {
+        let character = received_character.char;
+        if !character.is_control() {
+            input.buffer.push(character);
+        }
+    }
+
+    if keys.just_pressed(KeyCode::Back) {
+        input.buffer.pop();
+    }
+
+    if keys.just_pressed(KeyCode::Return) {
+        if let Some(command) = ExecuteCommand::parse(&input.buffer) {
+            commands.send(command);
+        }
+        input.buffer.clear();
+    }
+
+    if let Some(section) = text.sections.first_mut() {
+        section.value.clone_from(&input.buffer);
+    }
+}
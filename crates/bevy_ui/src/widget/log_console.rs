@@ -0,0 +1,286 @@
+use std::collections::VecDeque;
+
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt};
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_log::{Level, LogEvent};
+use bevy_render::color::Color;
+use bevy_text::{Text, TextStyle};
+
+use crate::{node_bundles::TextBundle, Interaction, Style};
+
+/// A single line retained by a [`LogConsole`], pre-formatted so we don't re-touch every
+/// [`LogEvent`] on each redraw.
+#[derive(Debug, Clone)]
+struct LogConsoleLine {
+    text: String,
+    color: Color,
+}
+
+fn level_color(level: Level) -> Color {
+    match level {
+        Level::TRACE => Color::PURPLE,
+        Level::DEBUG => Color::BLUE,
+        Level::INFO => Color::GREEN,
+        Level::WARN => Color::YELLOW,
+        Level::ERROR => Color::RED,
+    }
+}
+
+/// A bounded, scrollable console that mirrors the [`LogEvent`] stream.
+///
+/// Unlike pushing a `TextSection` per event onto a growing `Text` forever, [`LogConsole`] keeps
+/// only the last `capacity` lines in a ring buffer and renders just the rows currently scrolled
+/// into view, so memory and layout cost stay bounded no matter how long the app runs.
+#[derive(Component, Debug, Clone)]
+pub struct LogConsole {
+    /// The maximum number of log lines retained.
+    pub capacity: usize,
+    /// How many lines are rendered at once. Set this to roughly the number of rows that fit the
+    /// node's height.
+    pub visible_rows: usize,
+    /// Whether the view should jump to the newest line whenever one arrives. Cleared as soon as
+    /// the user scrolls up, and restored once they scroll back down to the bottom.
+    pub auto_scroll_to_bottom: bool,
+    lines: VecDeque<LogConsoleLine>,
+    /// How many lines the view is scrolled up from the bottom.
+    scroll_offset: usize,
+}
+
+impl Default for LogConsole {
+    fn default() -> Self {
+        Self {
+            capacity: 200,
+            visible_rows: 20,
+            auto_scroll_to_bottom: true,
+            lines: VecDeque::new(),
+            scroll_offset: 0,
+        }
+    }
+}
+
+impl LogConsole {
+    /// Creates a [`LogConsole`] that keeps the last `capacity` lines and renders `visible_rows`
+    /// of them at a time.
+    pub fn new(capacity: usize, visible_rows: usize) -> Self {
+        Self {
+            capacity,
+            visible_rows,
+            ..Default::default()
+        }
+    }
+
+    fn push(&mut self, event: &LogEvent) {
+        let evicted_a_line = self.lines.len() == self.capacity;
+        if evicted_a_line {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(LogConsoleLine {
+            text: event.message.clone(),
+            color: level_color(event.level),
+        });
+
+        if self.auto_scroll_to_bottom {
+            self.scroll_offset = 0;
+        } else {
+            // A new line was added at the bottom (whether or not the oldest one was evicted), so
+            // the "offset from the bottom" needs to grow by one too, or the window would silently
+            // drift towards the newest line on every incoming event instead of staying pinned to
+            // what the user was reading. Once the lines being read are evicted, the clamp keeps
+            // the view on the oldest retained lines.
+            self.scroll_offset = (self.scroll_offset + 1).min(self.max_scroll_offset());
+        }
+    }
+
+    fn max_scroll_offset(&self) -> usize {
+        self.lines.len().saturating_sub(self.visible_rows)
+    }
+
+    /// Scrolls by `delta` lines; positive scrolls up (towards older lines).
+    fn scroll_by(&mut self, delta: isize) {
+        let max = self.max_scroll_offset();
+        let offset = (self.scroll_offset as isize + delta).clamp(0, max as isize) as usize;
+        self.scroll_offset = offset;
+        self.auto_scroll_to_bottom = offset == 0;
+    }
+
+    fn visible(&self) -> impl Iterator<Item = &LogConsoleLine> {
+        let end = self.lines.len().saturating_sub(self.scroll_offset);
+        let start = end.saturating_sub(self.visible_rows);
+        self.lines.iter().skip(start).take(end - start)
+    }
+}
+
+/// Appends newly-fired [`LogEvent`]s to every [`LogConsole`] in the world.
+pub fn update_log_console_system(
+    mut events: EventReader<LogEvent>,
+    mut consoles: Query<&mut LogConsole>,
+) {
+    if events.is_empty() {
+        return;
+    }
+    let events: Vec<_> = events.read().collect();
+    for mut console in &mut consoles {
+        for event in &events {
+            console.push(event);
+        }
+    }
+}
+
+/// Scrolls hovered [`LogConsole`]s in response to [`MouseWheel`] input.
+pub fn scroll_log_console_system(
+    mut mouse_wheel_events: EventReader<MouseWheel>,
+    mut consoles: Query<(&Interaction, &mut LogConsole)>,
+) {
+    // A rough pixel-to-line conversion for `MouseScrollUnit::Pixel`, matching roughly one text
+    // row's height.
+    const PIXELS_PER_LINE: f32 = 20.0;
+
+    for wheel in mouse_wheel_events.read() {
+        let delta_lines = match wheel.unit {
+            MouseScrollUnit::Line => wheel.y,
+            MouseScrollUnit::Pixel => wheel.y / PIXELS_PER_LINE,
+        };
+        if delta_lines == 0.0 {
+            continue;
+        }
+        for (interaction, mut console) in &mut consoles {
+            if *interaction != Interaction::None {
+                console.scroll_by(delta_lines.round() as isize);
+            }
+        }
+    }
+}
+
+/// Rebuilds the visible window of a [`LogConsole`] as child text nodes whenever it changes.
+pub fn render_log_console_system(
+    mut commands: Commands,
+    consoles: Query<(Entity, &LogConsole, Option<&Children>), Changed<LogConsole>>,
+) {
+    for (entity, console, children) in &consoles {
+        if let Some(children) = children {
+            for &child in children {
+                commands.entity(child).despawn_recursive();
+            }
+        }
+
+        commands.entity(entity).with_children(|parent| {
+            for line in console.visible() {
+                parent.spawn(TextBundle {
+                    text: Text::from_section(
+                        line.text.clone(),
+                        TextStyle {
+                            font_size: 16.0,
+                            color: line.color,
+                            ..Default::default()
+                        },
+                    ),
+                    style: Style::default(),
+                    ..Default::default()
+                });
+            }
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn event(message: &str, level: Level) -> LogEvent {
+        LogEvent {
+            message: message.to_string(),
+            fields: HashMap::new(),
+            name: "test",
+            target: "test".to_string(),
+            level,
+            module_path: None,
+            file: None,
+            line: None,
+        }
+    }
+
+    fn push_lines(console: &mut LogConsole, messages: &[&str]) {
+        for message in messages {
+            console.push(&event(message, Level::INFO));
+        }
+    }
+
+    #[test]
+    fn visible_window_tracks_the_newest_lines_by_default() {
+        let mut console = LogConsole::new(10, 3);
+        push_lines(&mut console, &["a", "b", "c", "d", "e"]);
+
+        let visible: Vec<_> = console.visible().map(|line| line.text.as_str()).collect();
+        assert_eq!(visible, vec!["c", "d", "e"]);
+    }
+
+    #[test]
+    fn capacity_evicts_the_oldest_lines() {
+        let mut console = LogConsole::new(3, 3);
+        push_lines(&mut console, &["a", "b", "c", "d"]);
+
+        let visible: Vec<_> = console.visible().map(|line| line.text.as_str()).collect();
+        assert_eq!(visible, vec!["b", "c", "d"]);
+    }
+
+    #[test]
+    fn scroll_by_is_clamped_to_the_available_history() {
+        let mut console = LogConsole::new(10, 2);
+        push_lines(&mut console, &["a", "b", "c", "d"]);
+
+        // There are 4 lines and 2 visible rows, so the max scroll offset is 2.
+        console.scroll_by(100);
+        assert_eq!(console.scroll_offset, 2);
+        assert!(!console.auto_scroll_to_bottom);
+
+        console.scroll_by(-100);
+        assert_eq!(console.scroll_offset, 0);
+        assert!(console.auto_scroll_to_bottom);
+    }
+
+    #[test]
+    fn scrolling_up_disables_auto_scroll_until_back_at_the_bottom() {
+        let mut console = LogConsole::new(10, 2);
+        push_lines(&mut console, &["a", "b", "c", "d"]);
+        assert!(console.auto_scroll_to_bottom);
+
+        console.scroll_by(1);
+        assert!(!console.auto_scroll_to_bottom);
+
+        // New lines shouldn't move the view while the user is scrolled up: capture the window
+        // before the push and compare it against the window after, rather than hard-coding an
+        // expected result that would silently accept the view drifting by a fixed offset.
+        let before: Vec<String> = console.visible().map(|line| line.text.clone()).collect();
+        console.push(&event("e", Level::INFO));
+        let after: Vec<String> = console.visible().map(|line| line.text.clone()).collect();
+        assert_eq!(before, after);
+        assert_eq!(before, vec!["b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn push_while_scrolled_up_keeps_the_window_pinned_across_many_events() {
+        let mut console = LogConsole::new(10, 2);
+        push_lines(&mut console, &["a", "b", "c", "d"]);
+        console.scroll_by(1);
+
+        let pinned: Vec<String> = console.visible().map(|line| line.text.clone()).collect();
+        for (i, message) in ["e", "f", "g"].iter().enumerate() {
+            console.push(&event(message, Level::INFO));
+            let visible: Vec<String> = console.visible().map(|line| line.text.clone()).collect();
+            assert_eq!(visible, pinned, "window drifted after push #{i}");
+        }
+    }
+
+    #[test]
+    fn push_with_auto_scroll_still_tracks_the_bottom() {
+        let mut console = LogConsole::new(10, 2);
+        push_lines(&mut console, &["a", "b", "c", "d"]);
+        assert!(console.auto_scroll_to_bottom);
+
+        console.push(&event("e", Level::INFO));
+        let visible: Vec<_> = console.visible().map(|line| line.text.as_str()).collect();
+        assert_eq!(visible, vec!["d", "e"]);
+    }
+}
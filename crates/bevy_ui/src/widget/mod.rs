@@ -0,0 +1,11 @@
+//! This module contains the systems that update the stuff related to UI.
+
+#[cfg(feature = "bevy_text")]
+mod console_input;
+#[cfg(feature = "bevy_text")]
+mod log_console;
+
+#[cfg(feature = "bevy_text")]
+pub use console_input::*;
+#[cfg(feature = "bevy_text")]
+pub use log_console::*;
@@ -31,6 +31,10 @@ fn setup(mut commands: Commands) {
         },
         ConsoleText,
     ));
+
+    // Structured fields (anything besides `message`) are captured into `LogEvent::fields` and
+    // shown by `log_system` below, instead of only being visible in the formatted message.
+    info!(player_id = 3, "joined the game");
 }
 
 // This system reads all incoming logs and then outputs them to the `ConsoleText` entity
@@ -41,6 +45,7 @@ fn log_system(
     let mut text = query.single_mut();
     for LogEvent {
         message,
+        fields,
         name,
         target,
         level,
@@ -50,7 +55,24 @@ fn log_system(
     } in log_events.read()
     {
         // This part is just pushing a bunch of `TextSection`s to the UI.
-        
+
+        if !fields.is_empty() {
+            let mut fields: Vec<_> = fields.iter().collect();
+            fields.sort_unstable_by_key(|(key, _)| *key);
+            let fields = fields
+                .into_iter()
+                .map(|(key, value)| format!("{key}={value}"))
+                .collect::<Vec<_>>()
+                .join(" ");
+            text.sections.push(TextSection {
+                value: format!("fields: `{fields}` "),
+                style: TextStyle {
+                    font_size: 16.0,
+                    color: Color::rgb(0.9, 0.9, 0.7),
+                    ..default()
+                },
+            });
+        }
         text.sections.push(TextSection {
             value: format!("file: `{file:?}`, line: {line:?} "),
             style: TextStyle {